@@ -0,0 +1,120 @@
+//! Arrhenius thermal-degradation integral for the matrix phase, and a
+//! helper that applies it to the transverse conductivities returned by
+//! [`crate::thermal_conductivity_for_unidirectional_composite`].
+
+use crate::{Error, Result};
+
+const GAS_CONSTANT: f64 = 8.314;
+
+/// The `Omega >= 1.0` damage integral threshold is the conventional onset
+/// of thermal damage for the Arrhenius model.
+pub const DEFAULT_DAMAGE_THRESHOLD: f64 = 1.0;
+
+/// Computes the cumulative thermal damage of the matrix from a
+/// time-temperature history, via the Arrhenius damage integral
+///
+/// `Omega = integral A * exp(-Ea / (R * T(t))) dt`
+///
+/// evaluated by trapezoidal integration over the `(times, temps_kelvin)`
+/// samples. `Omega >= 1.0` flags the onset of damage.
+///
+/// ## Arguments
+///
+/// * `times` - sample times, in ascending order.
+/// * `temps_kelvin` - temperature at each sample time, in Kelvin.
+/// * `a_frequency` - pre-exponential (frequency) factor `A`.
+/// * `ea_activation` - activation energy `Ea`, in J/mol.
+pub fn arrhenius_damage(
+    times: &[f64],
+    temps_kelvin: &[f64],
+    a_frequency: f64,
+    ea_activation: f64,
+) -> Result<f64> {
+    if times.len() != temps_kelvin.len() {
+        return Err(Error::NumericalError(Box::new(
+            "times and temps_kelvin must have the same length",
+        )));
+    }
+    if !times.windows(2).all(|w| w[1] >= w[0]) {
+        return Err(Error::NumericalError(Box::new(
+            "times must be given in ascending order",
+        )));
+    }
+
+    std::panic::catch_unwind(|| {
+        let rate_at = |temperature_kelvin: f64| {
+            a_frequency * (-ea_activation / (GAS_CONSTANT * temperature_kelvin)).exp()
+        };
+
+        times
+            .windows(2)
+            .zip(temps_kelvin.windows(2))
+            .map(|(time_window, temp_window)| {
+                let dt = time_window[1] - time_window[0];
+                let rate0 = rate_at(temp_window[0]);
+                let rate1 = rate_at(temp_window[1]);
+                0.5 * (rate0 + rate1) * dt
+            })
+            .sum()
+    })
+    .map_err(Error::NumericalError)
+}
+
+/// Knocks the matrix-dominated transverse conductivities `k2, k3` down by
+/// `knockdown_factor` once the damage integral `omega` reaches `threshold`,
+/// leaving the fiber-dominated `k1` untouched.
+pub fn degrade_transverse_conductivity(
+    conductivities: [f64; 3],
+    omega: f64,
+    threshold: f64,
+    knockdown_factor: f64,
+) -> [f64; 3] {
+    let [k1, k2, k3] = conductivities;
+    if omega >= threshold {
+        [k1, k2 * knockdown_factor, k3 * knockdown_factor]
+    } else {
+        [k1, k2, k3]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let omega = arrhenius_damage(&[0.0, 1.0, 2.0], &[300.0, 350.0, 400.0], 1e10, 80000.0)
+            .unwrap();
+        assert_eq!(omega, 0.19005707971272562);
+    }
+
+    #[test]
+    fn damage_grows_monotonically_with_a_longer_history() {
+        let short = arrhenius_damage(&[0.0, 1.0], &[300.0, 400.0], 1e10, 80000.0).unwrap();
+        let long =
+            arrhenius_damage(&[0.0, 1.0, 2.0], &[300.0, 400.0, 500.0], 1e10, 80000.0).unwrap();
+        assert!(long > short);
+    }
+
+    #[test]
+    fn mismatched_lengths_are_rejected() {
+        assert!(arrhenius_damage(&[0.0, 1.0], &[300.0], 1e10, 80000.0).is_err());
+    }
+
+    #[test]
+    fn out_of_order_times_are_rejected() {
+        assert!(arrhenius_damage(&[1.0, 0.0], &[300.0, 400.0], 1e10, 80000.0).is_err());
+    }
+
+    #[test]
+    fn degrade_transverse_conductivity_knocks_down_past_threshold() {
+        let conductivities = [20.8, 1.33, 1.33];
+        let unaffected =
+            degrade_transverse_conductivity(conductivities, 0.5, DEFAULT_DAMAGE_THRESHOLD, 0.5);
+        assert_eq!(unaffected, conductivities);
+
+        let degraded =
+            degrade_transverse_conductivity(conductivities, 1.5, DEFAULT_DAMAGE_THRESHOLD, 0.5);
+        assert_eq!(degraded, [20.8, 0.665, 0.665]);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::{Error, Result};
+use enum_primitive_derive::Primitive;
+use num_traits::FromPrimitive;
+
+#[derive(Primitive)]
+enum Model {
+    // Соотношения Гибсона-Эшби для сотовых структур (Gibson, Ashby, "Cellular Solids", гл. 4)
+    GibsonAshby = 1,
+}
+
+/// Computes the in-plane [elastic moduli] of a hexagonal honeycomb core,
+/// using the Gibson-Ashby cellular-solid relations.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model, represented by the discriminant in [`Model`].
+/// * `l_cell_side_size` - the length `l` of the inclined cell wall.
+/// * `h_cell_side_size` - the length `h` of the vertical cell wall.
+/// * `wall_thickness` - the cell wall thickness `t`.
+/// * `angle` - the inclination angle `theta` of the inclined cell wall, in radians.
+/// * `e_for_honeycomb` - [Young's modulus] `Es` of the solid cell-wall material.
+///
+/// ## Returns
+///
+/// Returns the array of honeycomb properties in the following order:
+///
+/// * `e1` - in-plane [elastic modulus] in the primary direction.
+/// * `e2` - in-plane [elastic modulus] in the secondary direction.
+/// * `g12` - in-plane shear modulus.
+/// * `relative_density` - the ratio of the core density to the solid-wall density, `rho* / rho_s`.
+///
+/// [elastic moduli]: https://en.wikipedia.org/wiki/Elastic_modulus
+/// [elastic modulus]: https://en.wikipedia.org/wiki/Elastic_modulus
+/// [Young's modulus]: https://en.wikipedia.org/wiki/Young%27s_modulus
+pub fn elastic_modules_for_honeycomb(
+    number_of_model: u8,
+    l_cell_side_size: f64,
+    h_cell_side_size: f64,
+    wall_thickness: f64,
+    angle: f64,
+    e_for_honeycomb: f64,
+) -> Result<[f64; 4]> {
+    let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
+
+    std::panic::catch_unwind(|| match model {
+        Model::GibsonAshby => {
+            let h_l = h_cell_side_size / l_cell_side_size;
+            let t_l = wall_thickness / l_cell_side_size;
+
+            let e1 = e_for_honeycomb * t_l.powi(3) * angle.cos()
+                / ((h_l + angle.sin()) * angle.sin() * angle.sin());
+            let e2 = e_for_honeycomb * t_l.powi(3) * (h_l + angle.sin())
+                / (angle.cos() * angle.cos() * angle.cos());
+            let g12 = e_for_honeycomb * t_l.powi(3) * (h_l + angle.sin())
+                / (h_l * h_l * (1.0 + 2.0 * h_l) * angle.cos());
+            let relative_density =
+                t_l * (h_l + 2.0) / (2.0 * angle.cos() * (h_l + angle.sin()));
+
+            [e1, e2, g12, relative_density]
+        }
+    })
+    .map_err(Error::NumericalError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test() {
+        let [e1, e2, g12, relative_density] = elastic_modules_for_honeycomb(
+            1,
+            9.24,
+            8.4619,
+            0.4,
+            std::f64::consts::PI / 6.0,
+            70e9,
+        )
+        .unwrap();
+        assert_eq!(e1, 13894850.218024798);
+        assert_eq!(e2, 12378529.586405812);
+        assert_eq!(g12, 3909395.764178938);
+        assert_eq!(relative_density, 0.05147362894973779);
+    }
+}
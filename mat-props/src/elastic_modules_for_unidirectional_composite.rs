@@ -0,0 +1,209 @@
+use crate::materials::MaterialDb;
+use crate::temperature::ROOM_TEMPERATURE_KELVIN;
+use crate::{Error, Result};
+use enum_primitive_derive::Primitive;
+use num_traits::FromPrimitive;
+
+#[derive(Primitive)]
+enum Model {
+    // Правило смеси (продольный модуль) + обратное правило смеси (поперечный модуль)
+    RuleOfMixtures = 1,
+    // Модель Ванина для тетрагональной укладки. Описанно в "Микромеханика композиционных материалов", стр. 192
+    Vanin = 2,
+    // Полуэмпирическая модель Халпина-Цая (Halpin-Tsai) для поперечного модуля
+    HalpinTsai = 3,
+    // Модель Чамиса (Chamis) для поперечного модуля, та же эмпирическая форма, что и для теплопроводности
+    Chamis = 4,
+}
+
+/// Computes the [elastic moduli] for unidirectional composite.
+///
+/// ## Arguments
+///
+/// * `number_of_model` - the number of the selected model, represented by the discriminant in [`Model`].
+/// * `fibre_content` - the fibre content in the range from `0.0` to `1.0` where `0.0` is the matrix and `1.0` is the fibre.
+/// * `e_for_fiber` - [Young's modulus] of the fiber material.
+/// * `nu_for_fiber` - [Poisson's ratio] of the fiber material.
+/// * `e_for_matrix` - [Young's modulus] of the matrix material.
+/// * `nu_for_matrix` - [Poisson's ratio] of the matrix material.
+///
+/// ## Returns
+///
+/// Returns the array of engineering constants in the following order:
+///
+/// * `e1` - [Young's modulus] in the primary (fiber) direction.
+/// * `e2` - [Young's modulus] in the secondary direction.
+/// * `e3` - [Young's modulus] in the tertiary direction.
+/// * `nu12` - [Poisson's ratio] relating the secondary direction to the primary one.
+/// * `nu13` - [Poisson's ratio] relating the tertiary direction to the primary one.
+///
+/// The [`Model::Vanin`] model computes only `e1` and `nu12`: it returns `e1`
+/// in the `e2` and `e3` slots and `nu12` in the `nu13` slot.
+///
+/// [elastic moduli]: https://en.wikipedia.org/wiki/Elastic_modulus
+/// [Young's modulus]: https://en.wikipedia.org/wiki/Young%27s_modulus
+/// [Poisson's ratio]: https://en.wikipedia.org/wiki/Poisson%27s_ratio
+pub fn elastic_modules_for_unidirectional_composite(
+    number_of_model: u8,
+    fibre_content: f64,
+    e_for_fiber: f64,
+    nu_for_fiber: f64,
+    e_for_matrix: f64,
+    nu_for_matrix: f64,
+) -> Result<[f64; 5]> {
+    let model = Model::from_u8(number_of_model).ok_or(Error::UnknownModel)?;
+
+    std::panic::catch_unwind(|| match model {
+        Model::RuleOfMixtures => {
+            let e1 = fibre_content * e_for_fiber + (1.0 - fibre_content) * e_for_matrix;
+            let e2 = 1.0 / (fibre_content / e_for_fiber + (1.0 - fibre_content) / e_for_matrix);
+            let nu12 = fibre_content * nu_for_fiber + (1.0 - fibre_content) * nu_for_matrix;
+            [e1, e2, e2, nu12, nu12]
+        }
+        Model::Vanin => {
+            let matrix_content = 1.0 - fibre_content;
+            let g_for_matrix = e_for_matrix / (2.0 * (1.0 + nu_for_matrix));
+            let k_for_fiber =
+                e_for_fiber / (2.0 * (1.0 + nu_for_fiber) * (1.0 - 2.0 * nu_for_fiber));
+            let k_for_matrix =
+                e_for_matrix / (2.0 * (1.0 + nu_for_matrix) * (1.0 - 2.0 * nu_for_matrix));
+            let denom =
+                fibre_content / k_for_matrix + matrix_content / k_for_fiber + 1.0 / g_for_matrix;
+
+            let e1 = fibre_content * e_for_fiber
+                + matrix_content * e_for_matrix
+                + (4.0 * fibre_content * matrix_content * (nu_for_fiber - nu_for_matrix).powi(2))
+                    / denom;
+            let nu12 = nu_for_fiber * fibre_content
+                + nu_for_matrix * matrix_content
+                + fibre_content * matrix_content * (nu_for_fiber - nu_for_matrix)
+                    * (1.0 / k_for_matrix - 1.0 / k_for_fiber)
+                    / denom;
+            [e1, e1, e1, nu12, nu12]
+        }
+        Model::HalpinTsai => {
+            let e1 = fibre_content * e_for_fiber + (1.0 - fibre_content) * e_for_matrix;
+            let nu12 = fibre_content * nu_for_fiber + (1.0 - fibre_content) * nu_for_matrix;
+            let xi = 2.0;
+            let eta = (e_for_fiber / e_for_matrix - 1.0) / (e_for_fiber / e_for_matrix + xi);
+            let e2 = e_for_matrix * (1.0 + xi * eta * fibre_content) / (1.0 - eta * fibre_content);
+            [e1, e2, e2, nu12, nu12]
+        }
+        Model::Chamis => {
+            let e1 = fibre_content * e_for_fiber + (1.0 - fibre_content) * e_for_matrix;
+            let nu12 = fibre_content * nu_for_fiber + (1.0 - fibre_content) * nu_for_matrix;
+            let sqrt_vf = fibre_content.sqrt();
+            let e2 = e_for_matrix
+                * ((1.0 - sqrt_vf)
+                    + sqrt_vf / (1.0 - sqrt_vf * (1.0 - e_for_matrix / e_for_fiber)));
+            [e1, e2, e2, nu12, nu12]
+        }
+    })
+    .map_err(Error::NumericalError)
+}
+
+/// Same as [`elastic_modules_for_unidirectional_composite`], but looks the
+/// fiber and matrix up by name in `db` instead of taking their properties as
+/// raw `f64`s. Temperature-dependent properties are evaluated at
+/// [`ROOM_TEMPERATURE_KELVIN`].
+pub fn elastic_modules_for_unidirectional_composite_named(
+    number_of_model: u8,
+    fibre_content: f64,
+    fiber: &str,
+    matrix: &str,
+    db: &MaterialDb,
+) -> Result<[f64; 5]> {
+    let fiber = db.get(fiber)?;
+    let matrix = db.get(matrix)?;
+    elastic_modules_for_unidirectional_composite(
+        number_of_model,
+        fibre_content,
+        fiber.e.value_at(ROOM_TEMPERATURE_KELVIN),
+        fiber.nu.value_at(ROOM_TEMPERATURE_KELVIN),
+        matrix.e.value_at(ROOM_TEMPERATURE_KELVIN),
+        matrix.nu.value_at(ROOM_TEMPERATURE_KELVIN),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rule_of_mixtures() {
+        let [e1, e2, e3, nu12, nu13] =
+            elastic_modules_for_unidirectional_composite(1, 0.2, 100.0, 0.3, 5.0, 0.2).unwrap();
+        assert_eq!(e1, 24.0);
+        assert_eq!(e2, 6.172839506172839);
+        assert_eq!(e3, e2);
+        assert_eq!(nu12, 0.22000000000000003);
+        assert_eq!(nu13, nu12);
+    }
+
+    #[test]
+    fn vanin() {
+        let [e1, e2, e3, nu12, nu13] =
+            elastic_modules_for_unidirectional_composite(2, 0.2, 100.0, 0.3, 5.0, 0.2).unwrap();
+        assert_eq!(e1, 24.011723329425557);
+        assert_eq!(e2, e1);
+        assert_eq!(e3, e1);
+        assert_eq!(nu12, 0.2281359906213365);
+        assert_eq!(nu13, nu12);
+    }
+
+    #[test]
+    fn halpin_tsai() {
+        let [e1, e2, e3, nu12, nu13] =
+            elastic_modules_for_unidirectional_composite(3, 0.2, 100.0, 0.3, 5.0, 0.2).unwrap();
+        assert_eq!(e1, 24.0);
+        assert_eq!(e2, 8.131868131868131);
+        assert_eq!(e3, e2);
+        assert_eq!(nu12, 0.22000000000000003);
+        assert_eq!(nu13, nu12);
+    }
+
+    #[test]
+    fn chamis() {
+        let [e1, e2, e3, nu12, nu13] =
+            elastic_modules_for_unidirectional_composite(4, 0.2, 100.0, 0.3, 5.0, 0.2).unwrap();
+        assert_eq!(e1, 24.0);
+        assert_eq!(e2, 6.651751397118623);
+        assert_eq!(e3, e2);
+        assert_eq!(nu12, 0.22000000000000003);
+        assert_eq!(nu13, nu12);
+    }
+
+    #[test]
+    fn named_looks_up_materials_by_name() {
+        let mut db = MaterialDb::with_defaults();
+        db.merge_str(
+            r#"
+materials:
+  test_fiber:
+    rho: 1.0
+    e: 100.0
+    nu: 0.3
+    alpha: 1e-6
+    k: 1.0
+  test_matrix:
+    rho: 1.0
+    e: 5.0
+    nu: 0.2
+    alpha: 1e-6
+    k: 1.0
+"#,
+        )
+        .unwrap();
+        let named = elastic_modules_for_unidirectional_composite_named(
+            1,
+            0.2,
+            "test_fiber",
+            "test_matrix",
+            &db,
+        )
+        .unwrap();
+        let raw =
+            elastic_modules_for_unidirectional_composite(1, 0.2, 100.0, 0.3, 5.0, 0.2).unwrap();
+        assert_eq!(named, raw);
+    }
+}
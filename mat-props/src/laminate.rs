@@ -0,0 +1,138 @@
+//! Classical Laminate Theory: ply-level stiffness transformed and assembled
+//! into the laminate `ABD` matrix, for validation against FEM
+//! stiffness-matrix methods.
+
+use crate::Result;
+
+/// A single ply in a laminate stack.
+#[derive(Debug, Clone, Copy)]
+pub struct Ply {
+    /// Ply angle, in radians, measured from the laminate `x` axis.
+    pub angle: f64,
+    /// Ply thickness.
+    pub thickness: f64,
+    /// Longitudinal Young's modulus, `E1`, in material axes.
+    pub e1: f64,
+    /// Transverse Young's modulus, `E2`, in material axes.
+    pub e2: f64,
+    /// Major Poisson's ratio, `nu12`, in material axes.
+    pub nu12: f64,
+    /// In-plane shear modulus, `G12`, in material axes.
+    pub g12: f64,
+}
+
+/// The laminate `A`, `B`, `D` stiffness blocks, each a `3x3` matrix over the
+/// `(1, 2, 6)` engineering stress/strain axes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AbdMatrix {
+    /// Extensional stiffness, relating mid-plane strain to in-plane force.
+    pub a: [[f64; 3]; 3],
+    /// Coupling stiffness, relating mid-plane strain/curvature to
+    /// moment/force.
+    pub b: [[f64; 3]; 3],
+    /// Bending stiffness, relating curvature to moment.
+    pub d: [[f64; 3]; 3],
+}
+
+/// Computes the reduced stiffness matrix `Q` of a ply in its own material
+/// axes, from the engineering constants `E1, E2, nu12, G12`.
+fn reduced_stiffness(e1: f64, e2: f64, nu12: f64, g12: f64) -> [[f64; 3]; 3] {
+    let nu21 = nu12 * e2 / e1;
+    let d = 1.0 - nu12 * nu21;
+    let q11 = e1 / d;
+    let q22 = e2 / d;
+    let q12 = nu12 * e2 / d;
+    let q66 = g12;
+    [[q11, q12, 0.0], [q12, q22, 0.0], [0.0, 0.0, q66]]
+}
+
+/// Transforms a ply's reduced stiffness `Q`, given in material axes, to the
+/// laminate `x, y` axes for a ply oriented at `angle` (in radians).
+fn transformed_stiffness(q: [[f64; 3]; 3], angle: f64) -> [[f64; 3]; 3] {
+    let (q11, q12, q66) = (q[0][0], q[0][1], q[2][2]);
+    let q22 = q[1][1];
+    let c = angle.cos();
+    let s = angle.sin();
+    let (c2, s2) = (c * c, s * s);
+
+    let qbar11 = q11 * c2 * c2 + 2.0 * (q12 + 2.0 * q66) * s2 * c2 + q22 * s2 * s2;
+    let qbar12 = (q11 + q22 - 4.0 * q66) * s2 * c2 + q12 * (s2 * s2 + c2 * c2);
+    let qbar22 = q11 * s2 * s2 + 2.0 * (q12 + 2.0 * q66) * s2 * c2 + q22 * c2 * c2;
+    let qbar16 = (q11 - q12 - 2.0 * q66) * s * c2 * c + (q12 - q22 + 2.0 * q66) * s2 * s * c;
+    let qbar26 = (q11 - q12 - 2.0 * q66) * s2 * s * c + (q12 - q22 + 2.0 * q66) * s * c2 * c;
+    let qbar66 = (q11 + q22 - 2.0 * q12 - 2.0 * q66) * s2 * c2 + q66 * (s2 * s2 + c2 * c2);
+
+    [
+        [qbar11, qbar12, qbar16],
+        [qbar12, qbar22, qbar26],
+        [qbar16, qbar26, qbar66],
+    ]
+}
+
+/// Assembles the laminate `ABD` matrix from a stack of plies, ordered from
+/// the bottom surface to the top surface.
+///
+/// Mid-plane `z` interfaces are derived from the ply thicknesses, and the
+/// `A`, `B`, `D` blocks are accumulated ply-by-ply:
+///
+/// * `A_ij = sum Qbar_ij (z_k - z_{k-1})`
+/// * `B_ij = 1/2 sum Qbar_ij (z_k^2 - z_{k-1}^2)`
+/// * `D_ij = 1/3 sum Qbar_ij (z_k^3 - z_{k-1}^3)`
+pub fn laminate_abd_matrix(plies: &[Ply]) -> Result<AbdMatrix> {
+    std::panic::catch_unwind(|| {
+        let total_thickness: f64 = plies.iter().map(|ply| ply.thickness).sum();
+        let mut z = -total_thickness / 2.0;
+
+        let mut a = [[0.0; 3]; 3];
+        let mut b = [[0.0; 3]; 3];
+        let mut d = [[0.0; 3]; 3];
+
+        for ply in plies {
+            let z_prev = z;
+            z += ply.thickness;
+
+            let q = reduced_stiffness(ply.e1, ply.e2, ply.nu12, ply.g12);
+            let qbar = transformed_stiffness(q, ply.angle);
+
+            for row in 0..3 {
+                for col in 0..3 {
+                    a[row][col] += qbar[row][col] * (z - z_prev);
+                    b[row][col] += 0.5 * qbar[row][col] * (z.powi(2) - z_prev.powi(2));
+                    d[row][col] += (1.0 / 3.0) * qbar[row][col] * (z.powi(3) - z_prev.powi(3));
+                }
+            }
+        }
+
+        AbdMatrix { a, b, d }
+    })
+    .map_err(crate::Error::NumericalError)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cross_ply_laminate() {
+        let ply = |angle| Ply {
+            angle,
+            thickness: 0.125e-3,
+            e1: 140e9,
+            e2: 10e9,
+            nu12: 0.3,
+            g12: 5e9,
+        };
+        let abd = laminate_abd_matrix(&[ply(0.0), ply(std::f64::consts::FRAC_PI_2)]).unwrap();
+
+        assert_eq!(abd.a[0][0], 18871315.600287564);
+        assert_eq!(abd.a[0][1], 754852.6240115025);
+        assert_eq!(abd.a[2][2], 1250000.0);
+
+        assert_eq!(abd.b[0][0], -1022.196261682243);
+        assert_eq!(abd.b[1][1], 1022.196261682243);
+
+        assert_eq!(abd.d[0][0], 0.09828810208483106);
+        assert_eq!(abd.d[0][1], 0.0039315240833932424);
+        assert_eq!(abd.d[2][2], 0.006510416666666666);
+    }
+}
@@ -0,0 +1,167 @@
+//! Named material database, loaded from YAML.
+//!
+//! The on-disk layout mirrors a DAMASK material-YAML file: a top-level
+//! `materials:` map keyed by material name, where each record carries a
+//! `rho:` density plus the per-property fields used throughout this crate,
+//! and a free-form `references:` list citing where the numbers came from.
+
+use crate::temperature::PropertyCurve;
+use crate::{Error, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// A single material record, as found under a material-YAML `materials:` entry.
+///
+/// `e`, `nu`, `alpha`, and `k` may each be given in YAML as a bare number, a
+/// polynomial in temperature, or a `(temperature, value)` table; see
+/// [`PropertyCurve`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct Material {
+    /// Mass density (`rho` in the DAMASK material-YAML layout).
+    pub rho: f64,
+    /// Young's modulus.
+    pub e: PropertyCurve,
+    /// Poisson's ratio.
+    pub nu: PropertyCurve,
+    /// Coefficient of thermal expansion.
+    pub alpha: PropertyCurve,
+    /// Thermal conductivity.
+    pub k: PropertyCurve,
+    /// Free-form list of literature references backing the values above.
+    #[serde(default)]
+    pub references: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+struct MaterialFile {
+    #[serde(default)]
+    materials: HashMap<String, Material>,
+}
+
+/// A registry of named materials, merged from the built-in default set and
+/// any number of user-supplied YAML files.
+#[derive(Debug, Clone, Default)]
+pub struct MaterialDb {
+    materials: HashMap<String, Material>,
+}
+
+impl MaterialDb {
+    /// Builds a database containing only the built-in materials (copper,
+    /// aluminium and iron).
+    pub fn with_defaults() -> Self {
+        let file: MaterialFile = serde_yaml::from_str(DEFAULT_MATERIALS_YAML)
+            .expect("built-in material YAML is well-formed");
+        Self {
+            materials: file.materials,
+        }
+    }
+
+    /// Merges the materials found in `yaml` over the current set,
+    /// overwriting any entry that shares a name with one already loaded.
+    pub fn merge_str(&mut self, yaml: &str) -> Result<()> {
+        let file: MaterialFile = serde_yaml::from_str(yaml).map_err(Error::Yaml)?;
+        self.materials.extend(file.materials);
+        Ok(())
+    }
+
+    /// Merges the materials found in the YAML file at `path` over the
+    /// current set.
+    pub fn merge_file(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let contents = std::fs::read_to_string(path).map_err(Error::Io)?;
+        self.merge_str(&contents)
+    }
+
+    /// Looks a material up by name.
+    pub fn get(&self, name: &str) -> Result<&Material> {
+        self.materials
+            .get(name)
+            .ok_or_else(|| Error::UnknownMaterial(name.to_owned()))
+    }
+}
+
+const DEFAULT_MATERIALS_YAML: &str = r#"
+materials:
+  copper:
+    rho: 8960.0
+    e: 110.0e9
+    nu: 0.34
+    alpha: 16.5e-6
+    k: 401.0
+    references:
+      - "ASM Metals Handbook, Vol. 2: Properties and Selection - Nonferrous Alloys"
+  aluminium:
+    rho: 2700.0
+    e: 69.0e9
+    nu: 0.33
+    alpha: 23.1e-6
+    k: 237.0
+    references:
+      - "ASM Metals Handbook, Vol. 2: Properties and Selection - Nonferrous Alloys"
+  iron:
+    rho: 7870.0
+    e: 211.0e9
+    nu: 0.29
+    alpha: 11.8e-6
+    k: 80.4
+    references:
+      - "ASM Metals Handbook, Vol. 1: Properties and Selection - Irons, Steels"
+"#;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_defaults_contains_builtin_materials() {
+        let db = MaterialDb::with_defaults();
+        let copper = db.get("copper").unwrap();
+        assert_eq!(copper.rho, 8960.0);
+        assert_eq!(copper.k.value_at(crate::temperature::ROOM_TEMPERATURE_KELVIN), 401.0);
+    }
+
+    #[test]
+    fn unknown_material_is_reported() {
+        let db = MaterialDb::with_defaults();
+        assert!(matches!(db.get("unobtainium"), Err(Error::UnknownMaterial(_))));
+    }
+
+    #[test]
+    fn merge_str_overrides_builtin_entries() {
+        let mut db = MaterialDb::with_defaults();
+        db.merge_str(
+            r#"
+materials:
+  copper:
+    rho: 9000.0
+    e: 115.0e9
+    nu: 0.34
+    alpha: 16.5e-6
+    k: 390.0
+"#,
+        )
+        .unwrap();
+        assert_eq!(db.get("copper").unwrap().rho, 9000.0);
+    }
+
+    #[test]
+    fn property_curve_can_be_a_temperature_table() {
+        let mut db = MaterialDb::with_defaults();
+        db.merge_str(
+            r#"
+materials:
+  test_alloy:
+    rho: 1.0
+    e:
+      - [273.0, 100.0]
+      - [373.0, 80.0]
+    nu: 0.3
+    alpha: 1e-6
+    k: 1.0
+"#,
+        )
+        .unwrap();
+        let test_alloy = db.get("test_alloy").unwrap();
+        assert_eq!(test_alloy.e.value_at(323.0), 90.0);
+    }
+}
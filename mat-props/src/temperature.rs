@@ -0,0 +1,112 @@
+//! Temperature-dependent material properties.
+//!
+//! The thermo-mechanical reference models this crate is built on drive
+//! properties with temperature (room temperature, pulse heating, ...), but
+//! the functions here otherwise take scalar constants. A [`PropertyCurve`]
+//! lets a property be supplied as a constant, a polynomial in temperature,
+//! or a tabulated `(temperature, value)` curve, and is evaluated at a given
+//! temperature with [`PropertyCurve::value_at`].
+
+use serde::Deserialize;
+
+/// Standard room temperature, in Kelvin, used as the evaluation point for
+/// functions that do not themselves take a temperature argument.
+pub const ROOM_TEMPERATURE_KELVIN: f64 = 293.15;
+
+/// A material property that may vary with temperature.
+///
+/// Deserializes from a bare number for [`PropertyCurve::Constant`], a flat
+/// list of coefficients for [`PropertyCurve::Polynomial`], or a list of
+/// `[temperature, value]` pairs for [`PropertyCurve::Table`].
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum PropertyCurve {
+    /// Temperature-independent value.
+    Constant(f64),
+    /// Polynomial in temperature (Kelvin), lowest-order coefficient first.
+    Polynomial(Vec<f64>),
+    /// Tabulated `(temperature_kelvin, value)` points, sorted ascending by
+    /// temperature.
+    Table(Vec<(f64, f64)>),
+}
+
+impl PropertyCurve {
+    /// Evaluates the curve at `temperature_kelvin`.
+    ///
+    /// Table curves are linearly interpolated between the two bracketing
+    /// points; temperatures outside the tabulated range are extrapolated by
+    /// clamping to the nearest endpoint.
+    pub fn value_at(&self, temperature_kelvin: f64) -> f64 {
+        match self {
+            PropertyCurve::Constant(value) => *value,
+            PropertyCurve::Polynomial(coefficients) => coefficients
+                .iter()
+                .rev()
+                .fold(0.0, |acc, coefficient| {
+                    acc * temperature_kelvin + coefficient
+                }),
+            PropertyCurve::Table(points) => interpolate_table(points, temperature_kelvin),
+        }
+    }
+}
+
+impl From<f64> for PropertyCurve {
+    fn from(value: f64) -> Self {
+        PropertyCurve::Constant(value)
+    }
+}
+
+fn interpolate_table(points: &[(f64, f64)], temperature_kelvin: f64) -> f64 {
+    let Some(&(first_t, first_v)) = points.first() else {
+        return 0.0;
+    };
+    if temperature_kelvin <= first_t {
+        return first_v;
+    }
+    let Some(&(last_t, last_v)) = points.last() else {
+        return 0.0;
+    };
+    if temperature_kelvin >= last_t {
+        return last_v;
+    }
+    for window in points.windows(2) {
+        let (t0, v0) = window[0];
+        let (t1, v1) = window[1];
+        if temperature_kelvin >= t0 && temperature_kelvin <= t1 {
+            let fraction = (temperature_kelvin - t0) / (t1 - t0);
+            return v0 + fraction * (v1 - v0);
+        }
+    }
+    last_v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_is_temperature_independent() {
+        let curve = PropertyCurve::Constant(42.0);
+        assert_eq!(curve.value_at(100.0), 42.0);
+        assert_eq!(curve.value_at(1000.0), 42.0);
+    }
+
+    #[test]
+    fn polynomial_is_evaluated_in_temperature() {
+        let curve = PropertyCurve::Polynomial(vec![1.0, 2.0, 3.0]);
+        assert_eq!(curve.value_at(2.0), 1.0 + 2.0 * 2.0 + 3.0 * 2.0 * 2.0);
+    }
+
+    #[test]
+    fn table_interpolates_linearly_between_points() {
+        let curve = PropertyCurve::Table(vec![(0.0, 0.0), (100.0, 10.0), (200.0, 10.0)]);
+        assert_eq!(curve.value_at(50.0), 5.0);
+    }
+
+    #[test]
+    fn table_extrapolation_clamps_to_endpoints() {
+        let curve = PropertyCurve::Table(vec![(0.0, 1.0), (100.0, 2.0)]);
+        assert_eq!(curve.value_at(-50.0), 1.0);
+        assert_eq!(curve.value_at(150.0), 2.0);
+    }
+}
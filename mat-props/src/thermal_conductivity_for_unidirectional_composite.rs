@@ -1,3 +1,5 @@
+use crate::materials::MaterialDb;
+use crate::temperature::ROOM_TEMPERATURE_KELVIN;
 use crate::{Error, Result};
 use core::f64::consts::PI;
 use enum_primitive_derive::Primitive;
@@ -9,6 +11,10 @@ enum Model {
     RuleOfMixtures = 1,
     // Модель Ванина для тетрагональной укладки. Описанно в "Микромеханика композиционных материалов", стр. 192
     Vanin = 2,
+    // Полуэмпирическая модель Халпина-Цая (Halpin-Tsai) для поперечной теплопроводности
+    HalpinTsai = 3,
+    // Модель Чамиса (Chamis), см. дипломную работу Thermal conductivity characterization of composite materials
+    Chamis = 4,
 }
 
 /// Computes [thermal conductivity] for unidirectional composite.
@@ -86,10 +92,77 @@ pub fn thermal_conductivity_for_unidirectional_composite(
                                     / (1.0 + k_for_fiber / k_for_matrix))));
             [k1, k2, k3]
         }
+        Model::HalpinTsai => {
+            let k1 = fibre_content * k_for_fiber + (1.0 - fibre_content) * k_for_matrix;
+            let xi = 2.0;
+            let eta = (k_for_fiber / k_for_matrix - 1.0) / (k_for_fiber / k_for_matrix + xi);
+            let k2 = k_for_matrix * (1.0 + xi * eta * fibre_content) / (1.0 - eta * fibre_content);
+            let k3 = k2;
+            [k1, k2, k3]
+        }
+        Model::Chamis => {
+            let k1 = fibre_content * k_for_fiber + (1.0 - fibre_content) * k_for_matrix;
+            let sqrt_vf = fibre_content.sqrt();
+            let k2 = k_for_matrix
+                * ((1.0 - sqrt_vf)
+                    + sqrt_vf / (1.0 - sqrt_vf * (1.0 - k_for_matrix / k_for_fiber)));
+            let k3 = k2;
+            [k1, k2, k3]
+        }
     })
     .map_err(Error::NumericalError)
 }
 
+/// Same as [`thermal_conductivity_for_unidirectional_composite`], but looks
+/// the fiber and matrix up by name in `db` instead of taking their
+/// conductivities as raw `f64`s. Temperature-dependent conductivities are
+/// evaluated at [`ROOM_TEMPERATURE_KELVIN`]; use
+/// [`thermal_conductivity_for_unidirectional_composite_sweep`] to evaluate
+/// them across a temperature range instead.
+pub fn thermal_conductivity_for_unidirectional_composite_named(
+    number_of_model: u8,
+    fibre_content: f64,
+    fiber: &str,
+    matrix: &str,
+    db: &MaterialDb,
+) -> Result<[f64; 3]> {
+    let fiber = db.get(fiber)?;
+    let matrix = db.get(matrix)?;
+    thermal_conductivity_for_unidirectional_composite(
+        number_of_model,
+        fibre_content,
+        fiber.k.value_at(ROOM_TEMPERATURE_KELVIN),
+        matrix.k.value_at(ROOM_TEMPERATURE_KELVIN),
+    )
+}
+
+/// Same as [`thermal_conductivity_for_unidirectional_composite_named`], but
+/// evaluates the named fiber and matrix conductivities at each temperature
+/// in `temps_kelvin` before running the base model, producing a
+/// property-vs-temperature table instead of a single operating point.
+pub fn thermal_conductivity_for_unidirectional_composite_sweep(
+    number_of_model: u8,
+    fibre_content: f64,
+    fiber: &str,
+    matrix: &str,
+    db: &MaterialDb,
+    temps_kelvin: &[f64],
+) -> Result<Vec<[f64; 3]>> {
+    let fiber = db.get(fiber)?;
+    let matrix = db.get(matrix)?;
+    temps_kelvin
+        .iter()
+        .map(|&t| {
+            thermal_conductivity_for_unidirectional_composite(
+                number_of_model,
+                fibre_content,
+                fiber.k.value_at(t),
+                matrix.k.value_at(t),
+            )
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -102,4 +175,89 @@ mod tests {
         assert_eq!(k2, 1.3300670235932428);
         assert_eq!(k3, 1.3300670235932428);
     }
+
+    #[test]
+    fn named_looks_up_materials_by_name() {
+        let mut db = MaterialDb::with_defaults();
+        db.merge_str(
+            r#"
+materials:
+  test_fiber:
+    rho: 1.0
+    e: 1.0
+    nu: 0.3
+    alpha: 1e-6
+    k: 100.0
+  test_matrix:
+    rho: 1.0
+    e: 1.0
+    nu: 0.3
+    alpha: 1e-6
+    k: 1.0
+"#,
+        )
+        .unwrap();
+        let named = thermal_conductivity_for_unidirectional_composite_named(
+            2,
+            0.2,
+            "test_fiber",
+            "test_matrix",
+            &db,
+        )
+        .unwrap();
+        let raw = thermal_conductivity_for_unidirectional_composite(2, 0.2, 100.0, 1.0).unwrap();
+        assert_eq!(named, raw);
+    }
+
+    #[test]
+    fn halpin_tsai() {
+        let [k1, k2, k3] =
+            thermal_conductivity_for_unidirectional_composite(3, 0.2, 100.0, 1.0).unwrap();
+        assert_eq!(k1, 20.8);
+        assert_eq!(k2, 1.7226277372262773);
+        assert_eq!(k3, 1.7226277372262773);
+    }
+
+    #[test]
+    fn chamis() {
+        let [k1, k2, k3] =
+            thermal_conductivity_for_unidirectional_composite(4, 0.2, 100.0, 1.0).unwrap();
+        assert_eq!(k1, 20.8);
+        assert_eq!(k2, 1.3553108398093259);
+        assert_eq!(k3, 1.3553108398093259);
+    }
+
+    #[test]
+    fn sweep_evaluates_one_point_per_temperature() {
+        let mut db = MaterialDb::with_defaults();
+        db.merge_str(
+            r#"
+materials:
+  test_fiber:
+    rho: 1.0
+    e: 1.0
+    nu: 0.3
+    alpha: 1e-6
+    k: 100.0
+  test_matrix:
+    rho: 1.0
+    e: 1.0
+    nu: 0.3
+    alpha: 1e-6
+    k: 1.0
+"#,
+        )
+        .unwrap();
+        let sweep = thermal_conductivity_for_unidirectional_composite_sweep(
+            2,
+            0.2,
+            "test_fiber",
+            "test_matrix",
+            &db,
+            &[ROOM_TEMPERATURE_KELVIN, ROOM_TEMPERATURE_KELVIN],
+        )
+        .unwrap();
+        assert_eq!(sweep.len(), 2);
+        assert_eq!(sweep[0], sweep[1]);
+    }
 }
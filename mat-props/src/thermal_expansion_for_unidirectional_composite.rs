@@ -1,3 +1,5 @@
+use crate::materials::MaterialDb;
+use crate::temperature::ROOM_TEMPERATURE_KELVIN;
 use crate::{elastic_modules_for_unidirectional_composite, Error, Result};
 use enum_primitive_derive::Primitive;
 use num_traits::FromPrimitive;
@@ -98,6 +100,64 @@ pub fn thermal_expansion_for_unidirectional_composite(
     .map_err(Error::NumericalError)
 }
 
+/// Same as [`thermal_expansion_for_unidirectional_composite`], but looks the
+/// fiber and matrix up by name in `db` instead of taking their properties as
+/// raw `f64`s. Temperature-dependent properties are evaluated at
+/// [`ROOM_TEMPERATURE_KELVIN`]; use
+/// [`thermal_expansion_for_unidirectional_composite_sweep`] to evaluate them
+/// across a temperature range instead.
+pub fn thermal_expansion_for_unidirectional_composite_named(
+    number_of_model: u8,
+    fibre_content: f64,
+    fiber: &str,
+    matrix: &str,
+    db: &MaterialDb,
+) -> Result<[f64; 3]> {
+    let fiber = db.get(fiber)?;
+    let matrix = db.get(matrix)?;
+    thermal_expansion_for_unidirectional_composite(
+        number_of_model,
+        fibre_content,
+        fiber.e.value_at(ROOM_TEMPERATURE_KELVIN),
+        fiber.nu.value_at(ROOM_TEMPERATURE_KELVIN),
+        fiber.alpha.value_at(ROOM_TEMPERATURE_KELVIN),
+        matrix.e.value_at(ROOM_TEMPERATURE_KELVIN),
+        matrix.nu.value_at(ROOM_TEMPERATURE_KELVIN),
+        matrix.alpha.value_at(ROOM_TEMPERATURE_KELVIN),
+    )
+}
+
+/// Same as [`thermal_expansion_for_unidirectional_composite_named`], but
+/// evaluates the named fiber and matrix properties at each temperature in
+/// `temps_kelvin` before running the base model, producing a
+/// property-vs-temperature table instead of a single operating point.
+pub fn thermal_expansion_for_unidirectional_composite_sweep(
+    number_of_model: u8,
+    fibre_content: f64,
+    fiber: &str,
+    matrix: &str,
+    db: &MaterialDb,
+    temps_kelvin: &[f64],
+) -> Result<Vec<[f64; 3]>> {
+    let fiber = db.get(fiber)?;
+    let matrix = db.get(matrix)?;
+    temps_kelvin
+        .iter()
+        .map(|&t| {
+            thermal_expansion_for_unidirectional_composite(
+                number_of_model,
+                fibre_content,
+                fiber.e.value_at(t),
+                fiber.nu.value_at(t),
+                fiber.alpha.value_at(t),
+                matrix.e.value_at(t),
+                matrix.nu.value_at(t),
+                matrix.alpha.value_at(t),
+            )
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -112,4 +172,73 @@ mod tests {
         assert_eq!(alpha2, 0.0001653038466333737);
         assert_eq!(alpha3, 0.0001653038466333737);
     }
+
+    #[test]
+    fn named_looks_up_materials_by_name() {
+        let mut db = MaterialDb::with_defaults();
+        db.merge_str(
+            r#"
+materials:
+  test_fiber:
+    rho: 1.0
+    e: 100.0
+    nu: 0.3
+    alpha: 1e-6
+    k: 1.0
+  test_matrix:
+    rho: 1.0
+    e: 5.0
+    nu: 0.2
+    alpha: 20e-5
+    k: 1.0
+"#,
+        )
+        .unwrap();
+        let named = thermal_expansion_for_unidirectional_composite_named(
+            1,
+            0.2,
+            "test_fiber",
+            "test_matrix",
+            &db,
+        )
+        .unwrap();
+        let raw =
+            thermal_expansion_for_unidirectional_composite(1, 0.2, 100.0, 0.3, 1e-6, 5.0, 0.2, 20e-5)
+                .unwrap();
+        assert_eq!(named, raw);
+    }
+
+    #[test]
+    fn sweep_evaluates_one_point_per_temperature() {
+        let mut db = MaterialDb::with_defaults();
+        db.merge_str(
+            r#"
+materials:
+  test_fiber:
+    rho: 1.0
+    e: 100.0
+    nu: 0.3
+    alpha: 1e-6
+    k: 1.0
+  test_matrix:
+    rho: 1.0
+    e: 5.0
+    nu: 0.2
+    alpha: 20e-5
+    k: 1.0
+"#,
+        )
+        .unwrap();
+        let sweep = thermal_expansion_for_unidirectional_composite_sweep(
+            1,
+            0.2,
+            "test_fiber",
+            "test_matrix",
+            &db,
+            &[ROOM_TEMPERATURE_KELVIN, ROOM_TEMPERATURE_KELVIN],
+        )
+        .unwrap();
+        assert_eq!(sweep.len(), 2);
+        assert_eq!(sweep[0], sweep[1]);
+    }
 }